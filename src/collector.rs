@@ -1,7 +1,11 @@
 use super::Result;
 
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{BTreeMap, BTreeSet};
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
 
 use serde_json;
 
@@ -17,18 +21,199 @@ pub(crate) struct Error {
     retryable: bool,
 }
 
+impl Error {
+    fn new(kind: ErrorKind, num_retries: u8) -> Self {
+        let retryable = kind.is_retryable();
+        Error {
+            kind,
+            num_retries,
+            max_retries: DEFAULT_MAX_RETRIES,
+            retryable,
+        }
+    }
+
+    /// Whether this failure has exhausted its retry budget, i.e. is permanent.
+    fn is_exhausted(&self) -> bool {
+        !self.retryable || self.num_retries >= self.max_retries
+    }
+}
+
 const DEFAULT_MAX_RETRIES: u8 = 2;
 
+/// Base backoff between retries; the actual delay grows linearly with `num_retries`.
+const RETRY_BACKOFF_BASE: Duration = Duration::from_secs(5);
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub(crate) enum ErrorKind {
     Run(String),
     PostProcess(String),
 }
 
+impl ErrorKind {
+    /// Classifies a failure as retryable (transient) or permanent.
+    ///
+    /// Transient I/O, a criterion output file that hasn't been written (or is still locked by
+    /// another process) yet, and measurement-variance hiccups are worth retrying. A hard
+    /// build/compile failure never gets better on its own, so it's treated as permanent.
+    fn is_retryable(&self) -> bool {
+        match self {
+            ErrorKind::Run(message) => Self::is_retryable_run_failure(message),
+            ErrorKind::PostProcess(message) => Self::is_retryable_postprocess_failure(message),
+        }
+    }
+
+    fn is_retryable_run_failure(message: &str) -> bool {
+        let message = message.to_lowercase();
+
+        let permanent_markers = ["error[e", "could not compile", "linking", "error: aborting"];
+        if permanent_markers.iter().any(|m| message.contains(m)) {
+            return false;
+        }
+
+        let transient_markers = [
+            "timed out",
+            "temporarily unavailable",
+            "connection reset",
+            "would block",
+            "os error 11",
+        ];
+        transient_markers.iter().any(|m| message.contains(m))
+    }
+
+    fn is_retryable_postprocess_failure(message: &str) -> bool {
+        let message = message.to_lowercase();
+
+        let transient_markers = [
+            // estimates.json hasn't been written yet, or is still being written to
+            "no such file or directory",
+            "os error 2",
+            // criterion output still locked by a concurrent run
+            "would block",
+            "resource temporarily unavailable",
+            // noisy measurements that criterion itself flagged
+            "unable to complete",
+            "variance",
+        ];
+        transient_markers.iter().any(|m| message.contains(m))
+    }
+}
+
+/// Default noise threshold below which a metric's change is treated as measurement noise rather
+/// than a genuine regression or improvement.
+const DEFAULT_NOISE_THRESHOLD: f64 = 0.05;
+
+/// Whether a benchmark's measured value got better, worse, or stayed the same between runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Change {
+    Improved,
+    Regressed,
+    NoChange,
+}
+
+/// The result of comparing one metric's latest estimate against its most recent prior
+/// measurement for the same benchmark.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChangeEstimate {
+    pub change: Change,
+    /// `(new.point_estimate - old.point_estimate) / old.point_estimate`, or `0.0` when
+    /// `old.point_estimate` is zero.
+    pub relative_change: f64,
+    pub old: Statistic,
+    pub new: Statistic,
+}
+
+/// Tracks which `binary_hash`es have produced estimates for a given benchmark's `runner`/
+/// `shield`, oldest first, so `compare` knows what to diff the latest run against.
+#[derive(Clone, Debug, Default)]
+struct History {
+    binary_hashes: Vec<Vec<u8>>,
+}
+
+/// A pluggable source of extra measurements collected alongside a benchmark's runtime.
+///
+/// A profiler wraps the window during which `rp.exec()` runs the benchmark binary and
+/// contributes named `Statistic`s that get merged into the `Estimates` map next to
+/// `"nanoseconds"`, so they flow through the existing storage/memoization path unchanged.
+///
+/// **Neither concrete implementation in this module is wired up to real sampling yet.**
+/// `SysMonitorProfiler` and `PerfProfiler` are trait scaffolding: `start` always fails (see
+/// their doc comments for why), and `exec_with_profilers` degrades that failure to "no stats
+/// from this profiler" rather than failing the run. So selecting `--profilers sys_monitor,perf`
+/// today runs successfully but silently contributes zero extra stats — it is not yet the RSS/
+/// CPU/perf-counter feature its name implies.
+pub trait Profiler {
+    /// A short, stable identifier used to name this profiler's statistics and to select it from
+    /// the command line, e.g. `--profilers sys_monitor,perf`.
+    fn name(&self) -> &'static str;
+
+    /// Starts sampling immediately before `rp.exec()` runs the benchmark binary.
+    fn start(&self, rp: &RunPlan) -> Result<Box<dyn ProfilerSession>>;
+}
+
+/// An in-progress profiling session, created by `Profiler::start` and torn down once
+/// `rp.exec()` returns.
+pub trait ProfilerSession {
+    /// Stops sampling and returns this profiler's named statistics for the run that just
+    /// finished.
+    fn finish(self: Box<Self>) -> Result<Estimates>;
+}
+
+/// Samples RSS and CPU usage of the benchmark process for the duration of a run.
+///
+/// Not implemented yet: `RunPlan::exec` runs the benchmark binary to completion and doesn't hand
+/// back the spawned process, so there's nothing to sample RSS/CPU off of. `start` fails with an
+/// explanatory error so the logs are clear about why `--profilers sys_monitor` produced no extra
+/// stats; `exec_with_profilers` treats that failure as "no stats from this profiler" rather than
+/// failing the benchmark run it was attached to.
+pub struct SysMonitorProfiler;
+
+impl Profiler for SysMonitorProfiler {
+    fn name(&self) -> &'static str {
+        "sys_monitor"
+    }
+
+    fn start(&self, _rp: &RunPlan) -> Result<Box<dyn ProfilerSession>> {
+        unimplemented_profiler_error("sys_monitor")
+    }
+}
+
+/// Captures hardware performance-counter deltas (cache-misses, instructions, branch-misses)
+/// over a run via the system's `perf stat`.
+///
+/// Not implemented yet, for the same reason as `SysMonitorProfiler`: wiring this up to real
+/// `perf stat` counters needs `RunPlan::exec` to expose the benchmark's command/pid, which it
+/// doesn't today. `start` fails with an explanatory error, but (as with `SysMonitorProfiler`)
+/// that only costs this profiler's own stats, not the benchmark run.
+pub struct PerfProfiler;
+
+impl Profiler for PerfProfiler {
+    fn name(&self) -> &'static str {
+        "perf"
+    }
+
+    fn start(&self, _rp: &RunPlan) -> Result<Box<dyn ProfilerSession>> {
+        unimplemented_profiler_error("perf")
+    }
+}
+
+fn unimplemented_profiler_error<T>(name: &str) -> Result<T> {
+    Err(::std::io::Error::new(
+        ::std::io::ErrorKind::Other,
+        format!(
+            "profiler `{}` is not implemented yet: `RunPlan::exec` doesn't expose the spawned \
+             benchmark process, so there's nothing to sample it off of; pass a different \
+             `--profilers` list",
+            name
+        ),
+    )
+    .into())
+}
+
 /// Runs benchmarks, memoizes their results, and allows results to be shared across multiple
 /// toolchains if the binaries they produce are identical.
 pub struct Collector {
     dir: PathBuf,
+    vcs: vcs::GitConfig,
 }
 
 impl Collector {
@@ -36,21 +221,75 @@ impl Collector {
         ::std::fs::create_dir_all(data_dir)?;
         Ok(Collector {
             dir: data_dir.to_path_buf(),
+            vcs: vcs::GitConfig::default(),
         })
     }
 
+    /// Configures how `run_benches_with_toolchain` treats the data directory's git worktree; see
+    /// `vcs::GitConfig`.
+    pub fn with_vcs(mut self, vcs: vcs::GitConfig) -> Self {
+        self.vcs = vcs;
+        self
+    }
+
+    /// Brings the data directory's git worktree up to date, so that a subsequent
+    /// `compute_builds_needed` sees results other machines have already pushed to a shared
+    /// results repo and can skip more plans with no work. A no-op unless `with_vcs` enabled
+    /// `pull`.
+    pub fn sync(&self) -> Result<()> {
+        self.vcs.prepare(&self.dir)
+    }
+
+    /// Runs every `RunPlan` in `run_plans` under `toolchain`, then (if configured via
+    /// `with_vcs`) commits and pushes everything the batch produced in one shot, rather than
+    /// once per plan, so a large batch costs one pull and one commit/push, not one of each per
+    /// benchmark.
+    ///
+    /// A single plan failing (e.g. a build error) does not abort the rest of the batch: every
+    /// plan is still attempted, and whatever succeeded is still committed/pushed, since each one
+    /// already wrote its own results to disk via `ensure_persisted`. Leaving those on disk but
+    /// uncommitted would strand real results as dirty local changes in a shared results repo. If
+    /// any plan failed, this still returns `Err` describing them after the commit/push, so
+    /// callers learn about the failures without losing the work that did succeed.
+    ///
+    /// `profilers` is currently a no-op for every shipped `Profiler` (see that trait's doc
+    /// comment) — it does not yet collect the RSS/CPU/perf-counter stats its name implies.
     pub fn run_benches_with_toolchain(
         &self,
         toolchain: Toolchain,
         run_plans: &[RunPlan],
+        profilers: &[Box<dyn Profiler>],
     ) -> Result<()> {
         let _guard = toolchain.ensure_installed()?;
 
+        self.vcs.prepare(&self.dir)?;
+
+        let mut failures = Vec::new();
         for rp in run_plans {
-            self.run(rp)?;
+            if let Err(why) = self.run(rp, profilers) {
+                warn!("{} failed and will be excluded from this batch's results: {}", rp, why);
+                failures.push(format!("{}: {}", rp, why));
+            }
         }
 
-        Ok(())
+        let message = self.batch_commit_message(&toolchain, run_plans)?;
+        self.vcs.persist(&self.dir, &message)?;
+
+        if failures.is_empty() {
+            return Ok(());
+        }
+
+        Err(::std::io::Error::new(
+            ::std::io::ErrorKind::Other,
+            format!(
+                "{}/{} benchmark(s) failed in this batch (results that did succeed were still \
+                 committed): {}",
+                failures.len(),
+                run_plans.len(),
+                failures.join("; ")
+            ),
+        )
+        .into())
     }
 
     pub fn compute_builds_needed(
@@ -104,34 +343,135 @@ impl Collector {
         &self,
         rp: &RunPlan,
         binary_hash: &[u8],
+        profilers: &[Box<dyn Profiler>],
     ) -> Result<Entry<measurement::Key, <measurement::Key as StorageKey>::Contents>> {
         let (mkey, maybe_existing) = self.existing_estimates(rp, binary_hash)?;
 
         Ok(match maybe_existing {
-            Some(e) => Entry::Existing(e),
+            Some(Ok(estimates)) => Entry::Existing(Ok(estimates)),
+            Some(Err(ref prev)) if prev.is_exhausted() => {
+                info!(
+                    "{} previously failed with no retries left ({}/{}); not re-running",
+                    rp, prev.num_retries, prev.max_retries
+                );
+                Entry::Existing(Err(prev.clone()))
+            }
+            Some(Err(prev)) => {
+                let res =
+                    self.exec_and_process_with_retries(rp, binary_hash, prev.num_retries + 1, profilers);
+                Entry::New(mkey, res, self.dir.clone())
+            }
             None => {
-                let res = rp
-                    .exec()
-                    .map_err(|why| Error {
-                        kind: ErrorKind::Run(why.to_string()),
-                        max_retries: DEFAULT_MAX_RETRIES,
-                        num_retries: 0,
-                        retryable: false,
-                    })
-                    .and_then(|()| {
-                        self.process(&rp).map_err(|why| Error {
-                            kind: ErrorKind::Run(why.to_string()),
-                            max_retries: DEFAULT_MAX_RETRIES,
-                            num_retries: 0,
-                            retryable: false,
-                        })
-                    });
-
+                let res = self.exec_and_process_with_retries(rp, binary_hash, 0, profilers);
                 Entry::New(mkey, res, self.dir.clone())
             }
         })
     }
 
+    fn measurement_key(rp: &RunPlan, binary_hash: &[u8]) -> measurement::Key {
+        measurement::Key::new(
+            binary_hash.to_vec(),
+            rp.benchmark.runner.clone(),
+            rp.shield.clone(),
+        )
+    }
+
+    /// Runs and post-processes `rp`, retrying with increasing backoff on retryable failures.
+    ///
+    /// `num_retries` is the number of attempts already spent on this benchmark, read back from a
+    /// persisted `Error`, so retries continue counting up towards `max_retries` rather than
+    /// resetting the budget. After every failed attempt, its `Error` (carrying the `num_retries`
+    /// of that attempt) is persisted through the same measurement-key storage path a successful run
+    /// uses, before sleeping through the backoff — so a crash or restart between retries resumes
+    /// from where it left off instead of starting over at `num_retries = 0`.
+    fn exec_and_process_with_retries(
+        &self,
+        rp: &RunPlan,
+        binary_hash: &[u8],
+        mut num_retries: u8,
+        profilers: &[Box<dyn Profiler>],
+    ) -> Result<Estimates, Error> {
+        loop {
+            let attempt = self
+                .exec_with_profilers(rp, profilers)
+                .map_err(|why| Error::new(ErrorKind::Run(why.to_string()), num_retries))
+                .and_then(|profiled| {
+                    self.process(&rp)
+                        .map_err(|why| {
+                            Error::new(ErrorKind::PostProcess(why.to_string()), num_retries)
+                        })
+                        .map(|mut estimates| {
+                            estimates.extend(profiled);
+                            estimates
+                        })
+                });
+
+            let err = match attempt {
+                Ok(estimates) => return Ok(estimates),
+                Err(err) => err,
+            };
+
+            let interim: ::std::result::Result<Estimates, Error> = Err(err.clone());
+            let mkey = Self::measurement_key(rp, binary_hash);
+            if let Err(why) = Entry::New(mkey, interim, self.dir.clone()).ensure_persisted() {
+                warn!("failed to persist retry state for {}: {}", rp, why);
+            }
+
+            if err.is_exhausted() {
+                return Err(err);
+            }
+
+            num_retries += 1;
+            let backoff = RETRY_BACKOFF_BASE * u32::from(num_retries);
+            warn!(
+                "{} failed ({:?}), retrying ({}/{}) after {:?}",
+                rp, err.kind, num_retries, err.max_retries, backoff
+            );
+            thread::sleep(backoff);
+        }
+    }
+
+    /// Wraps `rp.exec()` with every enabled profiler's sampling window, returning whatever extra
+    /// `Estimates` they contributed so the caller can merge them into `process`'s output
+    /// alongside `"nanoseconds"`.
+    ///
+    /// A profiler that fails to start or to report its stats back only loses its own
+    /// contribution to `Estimates`; it does not stop `rp.exec()` from running or fail the
+    /// benchmark. Selecting a profiler that isn't implemented (or one that glitches on a given
+    /// run) should degrade to "no stats from this profiler", not take the whole run down with it.
+    fn exec_with_profilers(
+        &self,
+        rp: &RunPlan,
+        profilers: &[Box<dyn Profiler>],
+    ) -> Result<Estimates> {
+        let sessions: Vec<Box<dyn ProfilerSession>> = profilers
+            .iter()
+            .filter_map(|profiler| match profiler.start(rp) {
+                Ok(session) => Some(session),
+                Err(why) => {
+                    warn!(
+                        "profiler `{}` failed to start ({}); continuing without its stats",
+                        profiler.name(),
+                        why
+                    );
+                    None
+                }
+            })
+            .collect();
+
+        rp.exec()?;
+
+        let mut estimates = Estimates::new();
+        for session in sessions {
+            match session.finish() {
+                Ok(stats) => estimates.extend(stats),
+                Err(why) => warn!("profiler failed to report its stats ({}); skipping", why),
+            }
+        }
+
+        Ok(estimates)
+    }
+
     fn existing_estimates(
         &self,
         rp: &RunPlan,
@@ -140,11 +480,7 @@ impl Collector {
         measurement::Key,
         Option<<measurement::Key as StorageKey>::Contents>,
     )> {
-        let mkey = measurement::Key::new(
-            binary_hash.to_vec(),
-            rp.benchmark.runner.clone(),
-            rp.shield.clone(),
-        );
+        let mkey = Self::measurement_key(rp, binary_hash);
 
         let found = mkey.get(&self.dir)?.map(|a| a.1);
         Ok((mkey, found))
@@ -157,21 +493,237 @@ impl Collector {
     /// directory already has their respsective outputs for the provided RunPlan.
     ///
     /// Assumes that the `RunPlan`'s toolchain has already been installed.
-    pub fn run(&self, rp: &RunPlan) -> Result<()> {
-        // TODO git cleanliness and update operations go here
-
+    ///
+    /// This does not touch git; callers that want the data directory's git worktree kept in
+    /// sync should go through `run_benches_with_toolchain`, which wraps a whole batch of plans
+    /// in a single pull-before/commit-after pair rather than one per plan.
+    ///
+    /// `profilers` is currently a no-op for every shipped `Profiler` (see that trait's doc
+    /// comment): passing `SysMonitorProfiler`/`PerfProfiler` here runs the benchmark normally
+    /// but contributes none of their intended RSS/CPU/perf-counter stats.
+    pub fn run(&self, rp: &RunPlan, profilers: &[Box<dyn Profiler>]) -> Result<()> {
         let binary_hash = self.compute_binary_hash(rp)?;
-        let estimates = self.compute_estimates(rp, &*binary_hash)?;
+        let estimates = self.compute_estimates(rp, &*binary_hash, profilers)?;
 
         binary_hash.ensure_persisted()?;
         estimates.ensure_persisted()?;
-
-        // TODO git commit/push operations go here
+        self.record_history(rp, &*binary_hash)?;
 
         info!("all done with {}", rp);
         Ok(())
     }
 
+    /// A structured commit message covering every plan a batch produced: the toolchain, and
+    /// each benchmark id with the binary hash it ran against.
+    fn batch_commit_message(&self, toolchain: &Toolchain, run_plans: &[RunPlan]) -> Result<String> {
+        let mut entries = Vec::new();
+        for rp in run_plans {
+            if let (_, Some(hash)) = self.existing_binary_hash(rp)? {
+                entries.push((rp.benchmark.crate_name.clone(), rp.benchmark.name.clone(), hash));
+            }
+        }
+
+        Ok(Self::format_batch_commit_message(
+            &format!("{:?}", toolchain),
+            run_plans.len(),
+            &entries,
+        ))
+    }
+
+    /// The pure formatting half of `batch_commit_message`, split out so it's testable without a
+    /// real `Collector`/`RunPlan`/`Toolchain` on disk.
+    fn format_batch_commit_message(
+        toolchain_label: &str,
+        plan_count: usize,
+        entries: &[(String, String, Vec<u8>)],
+    ) -> String {
+        let mut lines = vec![format!("{}: {} benchmark(s)", toolchain_label, plan_count)];
+
+        for (crate_name, name, hash) in entries {
+            lines.push(format!("- {}::{} ({})", crate_name, name, vcs::hex(hash)));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Compares `rp`'s latest stored estimates against its most recent previous run (same
+    /// `runner`/`shield`, but an older `binary_hash`), using the default noise threshold.
+    ///
+    /// Returns an empty map if there's no current estimate yet, or no prior run to compare
+    /// against.
+    pub fn compare(&self, rp: &RunPlan) -> Result<BTreeMap<String, ChangeEstimate>> {
+        self.compare_with_threshold(rp, DEFAULT_NOISE_THRESHOLD)
+    }
+
+    /// Like `compare`, but with a caller-chosen noise threshold instead of the default ±5%.
+    pub fn compare_with_threshold(
+        &self,
+        rp: &RunPlan,
+        noise_threshold: f64,
+    ) -> Result<BTreeMap<String, ChangeEstimate>> {
+        let mut changes = BTreeMap::new();
+
+        let (_, maybe_hash) = self.existing_binary_hash(rp)?;
+        let hash = match maybe_hash {
+            Some(hash) => hash,
+            None => return Ok(changes),
+        };
+
+        let (_, maybe_new) = self.existing_estimates(rp, &hash)?;
+        let new = match maybe_new {
+            Some(Ok(new)) => new,
+            _ => return Ok(changes),
+        };
+
+        let old = match self.most_recent_previous_estimates(rp, &hash)? {
+            Some(old) => old,
+            None => return Ok(changes),
+        };
+
+        for (metric, new_stat) in &new {
+            if let Some(old_stat) = old.get(metric) {
+                changes.insert(
+                    metric.clone(),
+                    Self::classify_change(old_stat, new_stat, noise_threshold),
+                );
+            }
+        }
+
+        Ok(changes)
+    }
+
+    /// Classifies the change between two estimates of the same metric.
+    ///
+    /// A metric only counts as `Improved`/`Regressed` when its relative point-estimate change
+    /// exceeds `noise_threshold` *and* the new and old confidence intervals don't overlap;
+    /// otherwise it's ordinary measurement noise.
+    fn classify_change(old: &Statistic, new: &Statistic, noise_threshold: f64) -> ChangeEstimate {
+        let intervals_overlap = new.confidence_interval.lower_bound
+            <= old.confidence_interval.upper_bound
+            && old.confidence_interval.lower_bound <= new.confidence_interval.upper_bound;
+
+        // `old.point_estimate` is the divisor below; a metric that was truly zero last time
+        // (plausible for a profiler-contributed count, e.g. cache-misses) has no meaningful
+        // *relative* change, so fall back to comparing the new point estimate against
+        // `noise_threshold` directly instead of dividing by zero into NaN/inf.
+        let relative_change = if old.point_estimate == 0.0 {
+            0.0
+        } else {
+            (new.point_estimate - old.point_estimate) / old.point_estimate
+        };
+
+        let change = if intervals_overlap {
+            Change::NoChange
+        } else if old.point_estimate == 0.0 {
+            if new.point_estimate.abs() < noise_threshold {
+                Change::NoChange
+            } else if new.point_estimate < 0.0 {
+                Change::Improved
+            } else {
+                Change::Regressed
+            }
+        } else if relative_change.abs() < noise_threshold {
+            Change::NoChange
+        } else if relative_change < 0.0 {
+            Change::Improved
+        } else {
+            Change::Regressed
+        };
+
+        ChangeEstimate {
+            change,
+            relative_change,
+            old: old.clone(),
+            new: new.clone(),
+        }
+    }
+
+    /// Finds the most recently recorded successful `Estimates` for `rp`'s runner/shield that
+    /// weren't produced by `current_hash`.
+    fn most_recent_previous_estimates(
+        &self,
+        rp: &RunPlan,
+        current_hash: &[u8],
+    ) -> Result<Option<Estimates>> {
+        let history = self.read_history(rp)?;
+
+        for hash in history.binary_hashes.iter().rev() {
+            if hash == current_hash {
+                continue;
+            }
+
+            if let (_, Some(Ok(estimates))) = self.existing_estimates(rp, hash)? {
+                return Ok(Some(estimates));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Appends `binary_hash` to the history of hashes seen for `rp`'s runner/shield, if it isn't
+    /// already the most recent entry.
+    ///
+    /// This is append-only rather than a read-modify-write of one JSON file, because the data
+    /// directory this writes into may be a results repo shared by several distributed
+    /// `Collector`s (see `vcs::GitConfig`) finishing around the same time. A read-modify-write
+    /// would let the last writer silently clobber an earlier one's entry; appending a single
+    /// line is one `write(2)` call, which POSIX guarantees is atomic for an `O_APPEND` file when
+    /// the write is smaller than `PIPE_BUF`, so concurrent writers' hashes all land intact.
+    fn record_history(&self, rp: &RunPlan, binary_hash: &[u8]) -> Result<()> {
+        let history = self.read_history(rp)?;
+        if history.binary_hashes.last().map(Vec::as_slice) == Some(binary_hash) {
+            return Ok(());
+        }
+
+        let path = self.history_path(rp);
+        if let Some(parent) = path.parent() {
+            ::std::fs::create_dir_all(parent)?;
+        }
+
+        use std::io::Write;
+        let mut file = ::std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        file.write_all(format!("{}\n", vcs::hex(binary_hash)).as_bytes())?;
+
+        Ok(())
+    }
+
+    fn read_history(&self, rp: &RunPlan) -> Result<History> {
+        let path = self.history_path(rp);
+
+        let binary_hashes = match ::std::fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(vcs::dehex)
+                .collect::<Result<Vec<_>>>()?,
+            Err(_) => Vec::new(),
+        };
+
+        Ok(History { binary_hashes })
+    }
+
+    /// A stable path identifying the `(crate_name, benchmark name, runner, shield)` this plan
+    /// measures, independent of `binary_hash`, so successive runs share the same history file.
+    ///
+    /// One hex-encoded hash per line, oldest first, rather than a JSON array, so `record_history`
+    /// can append a new entry with a single atomic `write(2)` instead of rewriting the whole file.
+    fn history_path(&self, rp: &RunPlan) -> PathBuf {
+        let id = format!(
+            "{}::{}::{:?}::{:?}",
+            rp.benchmark.crate_name, rp.benchmark.name, rp.benchmark.runner, rp.shield
+        );
+
+        let mut hasher = DefaultHasher::new();
+        id.hash(&mut hasher);
+
+        self.dir
+            .join("history")
+            .join(format!("{:x}.history", hasher.finish()))
+    }
+
     /// Parses the results of a benchmark. This assumes that the benchmark has already been
     /// executed.
     fn process(&self, rp: &RunPlan) -> Result<Estimates> {
@@ -220,4 +772,1104 @@ impl Collector {
 
         Ok(metrics_estimates)
     }
+
+    /// Reads back already-persisted estimates for `rp`, if any, without building or running
+    /// anything.
+    ///
+    /// Returns `None` if there's no stored binary hash yet, no stored estimates for that hash,
+    /// or the stored entry is a failure rather than a successful measurement.
+    pub(crate) fn stored_estimates(&self, rp: &RunPlan) -> Result<Option<Estimates>> {
+        let (_, maybe_hash) = self.existing_binary_hash(rp)?;
+        let hash = match maybe_hash {
+            Some(hash) => hash,
+            None => return Ok(None),
+        };
+
+        let (_, maybe_estimates) = self.existing_estimates(rp, &hash)?;
+        Ok(match maybe_estimates {
+            Some(Ok(estimates)) => Some(estimates),
+            _ => None,
+        })
+    }
+
+    /// Exports the full data directory into `writer`, e.g. to ship a reproducible results
+    /// bundle to another machine or CI runner. Pick `snapshot::LooseSnapshotWriter` for a
+    /// browsable directory tree, or `snapshot::PackedSnapshotWriter` for a single portable
+    /// archive file.
+    pub fn export_snapshot(&self, mut writer: Box<dyn snapshot::SnapshotWriter>) -> Result<()> {
+        for entry in self.walk_dir_entries()? {
+            writer.write_entry(entry)?;
+        }
+        writer.finish()
+    }
+
+    /// Restores the data directory's contents from `reader`.
+    ///
+    /// Refuses to clobber an existing, populated data directory unless `backup` is `true`, in
+    /// which case the existing directory is moved aside to `<dir>.bak-<n>` first. Entries are
+    /// applied in whatever order `reader` yields them; since each is just a file keyed by its
+    /// own path, import order doesn't matter.
+    pub fn restore_snapshot(
+        &self,
+        reader: Box<dyn snapshot::SnapshotReader>,
+        backup: bool,
+    ) -> Result<()> {
+        self.refuse_if_populated(backup)?;
+
+        for entry in reader.read_entries()? {
+            snapshot::verify_entry_path(&entry.path)?;
+            snapshot::verify_entry_contents(&entry)?;
+
+            let path = self.dir.join(&entry.path);
+            if let Some(parent) = path.parent() {
+                ::std::fs::create_dir_all(parent)?;
+            }
+            ::std::fs::write(path, &entry.contents)?;
+        }
+
+        Ok(())
+    }
+
+    fn refuse_if_populated(&self, backup: bool) -> Result<()> {
+        if !self.dir_is_populated()? {
+            return Ok(());
+        }
+
+        if !backup {
+            return Err(::std::io::Error::new(
+                ::std::io::ErrorKind::AlreadyExists,
+                format!(
+                    "refusing to restore a snapshot over the non-empty data directory at {}; \
+                     pass `backup: true` to move the existing directory aside first",
+                    self.dir.display()
+                ),
+            )
+            .into());
+        }
+
+        self.back_up_dir()
+    }
+
+    fn dir_is_populated(&self) -> Result<bool> {
+        Ok(::std::fs::read_dir(&self.dir)?.next().is_some())
+    }
+
+    fn back_up_dir(&self) -> Result<()> {
+        let name = self
+            .dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("data")
+            .to_owned();
+
+        let mut n = 0u32;
+        loop {
+            let candidate = self.dir.with_file_name(format!("{}.bak-{}", name, n));
+            if !candidate.exists() {
+                info!(
+                    "backing up existing data directory to {}",
+                    candidate.display()
+                );
+                ::std::fs::rename(&self.dir, &candidate)?;
+                ::std::fs::create_dir_all(&self.dir)?;
+                return Ok(());
+            }
+            n += 1;
+        }
+    }
+
+    fn walk_dir_entries(&self) -> Result<Vec<snapshot::SnapshotEntry>> {
+        let mut entries = Vec::new();
+        snapshot::walk_dir_into(&self.dir, &self.dir, &mut entries)?;
+        Ok(entries)
+    }
+}
+
+/// Exports and imports a `Collector`'s data directory as a portable snapshot, either as a
+/// browsable "loose" directory tree or a single "packed" archive file.
+pub mod snapshot {
+    use std::fs;
+    use std::io::Write;
+    use std::path::{Component, Path, PathBuf};
+
+    use serde_json;
+
+    use super::{vcs, Error, Estimates, Result};
+
+    /// A single file from the data directory, identified by its path relative to the
+    /// `Collector`'s `dir`.
+    #[derive(Clone, Debug)]
+    pub struct SnapshotEntry {
+        pub path: PathBuf,
+        pub contents: Vec<u8>,
+    }
+
+    /// Destination for an `export_snapshot` call.
+    pub trait SnapshotWriter {
+        fn write_entry(&mut self, entry: SnapshotEntry) -> Result<()>;
+
+        /// Called once after every entry has been written, to flush/finalize the snapshot.
+        fn finish(self: Box<Self>) -> Result<()>;
+    }
+
+    /// Source for a `restore_snapshot` call.
+    pub trait SnapshotReader {
+        fn read_entries(self: Box<Self>) -> Result<Vec<SnapshotEntry>>;
+    }
+
+    /// Rejects an entry whose relative path would escape the data directory (e.g. via a `..`
+    /// component or an absolute path) before it's written.
+    pub(crate) fn verify_entry_path(relative: &Path) -> Result<()> {
+        if relative.is_absolute() {
+            return invalid_entry_error(relative, "it is an absolute path");
+        }
+
+        let escapes_root = relative
+            .components()
+            .any(|component| match component {
+                Component::ParentDir => true,
+                _ => false,
+            });
+
+        if escapes_root {
+            return invalid_entry_error(relative, "it contains a `..` component");
+        }
+
+        Ok(())
+    }
+
+    /// Confirms an entry's bytes match the shape implied by its path's top-level category before
+    /// they're written into the data directory: an `index/*` entry must decode as a binary hash
+    /// (`Vec<u8>`), a `measurement/*` entry as a `Result<Estimates, Error>`, and a `history/*`
+    /// entry as newline-separated hex-encoded hashes (see `Collector::record_history`); anything
+    /// else falls back to a plain JSON well-formedness check.
+    ///
+    /// This catches corruption from a truncated/bit-flipped packed archive, and a cross-category
+    /// path swap (e.g. a `measurement` entry's bytes re-imported under an `index` path). It does
+    /// NOT catch two entries *within* the same category being swapped with each other (e.g. two
+    /// different `index/<hash>` entries, which share the same `Vec<u8>` shape) — that would need
+    /// `index`/`measurement` to expose a way to recompute a key from its own stored contents,
+    /// which they don't today.
+    pub(crate) fn verify_entry_contents(entry: &SnapshotEntry) -> Result<()> {
+        let category = entry
+            .path
+            .components()
+            .next()
+            .and_then(|component| component.as_os_str().to_str());
+
+        let well_formed = match category {
+            Some("index") => serde_json::from_slice::<Vec<u8>>(&entry.contents).is_ok(),
+            Some("measurement") => {
+                serde_json::from_slice::<::std::result::Result<Estimates, Error>>(&entry.contents)
+                    .is_ok()
+            }
+            Some("history") => ::std::str::from_utf8(&entry.contents)
+                .map(|contents| {
+                    contents
+                        .lines()
+                        .filter(|line| !line.is_empty())
+                        .all(|line| vcs::dehex(line).is_ok())
+                })
+                .unwrap_or(false),
+            _ => serde_json::from_slice::<serde_json::Value>(&entry.contents).is_ok(),
+        };
+
+        if !well_formed {
+            return invalid_entry_error(
+                &entry.path,
+                "its contents don't match the shape its path implies",
+            );
+        }
+
+        Ok(())
+    }
+
+    fn invalid_entry_error<T>(path: &Path, why: &str) -> Result<T> {
+        Err(::std::io::Error::new(
+            ::std::io::ErrorKind::InvalidData,
+            format!(
+                "refusing to restore snapshot entry {}: {}",
+                path.display(),
+                why
+            ),
+        )
+        .into())
+    }
+
+    pub(crate) fn walk_dir_into(
+        root: &Path,
+        dir: &Path,
+        entries: &mut Vec<SnapshotEntry>,
+    ) -> Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if path.is_dir() {
+                walk_dir_into(root, &path, entries)?;
+            } else {
+                let relative = path
+                    .strip_prefix(root)
+                    .expect("walked path is under root")
+                    .to_path_buf();
+                let contents = fs::read(&path)?;
+                entries.push(SnapshotEntry {
+                    path: relative,
+                    contents,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a snapshot out as a browsable directory tree mirroring the data directory.
+    pub struct LooseSnapshotWriter {
+        root: PathBuf,
+    }
+
+    impl LooseSnapshotWriter {
+        pub fn new(root: PathBuf) -> Self {
+            LooseSnapshotWriter { root }
+        }
+    }
+
+    impl SnapshotWriter for LooseSnapshotWriter {
+        fn write_entry(&mut self, entry: SnapshotEntry) -> Result<()> {
+            let path = self.root.join(&entry.path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, &entry.contents)?;
+            Ok(())
+        }
+
+        fn finish(self: Box<Self>) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Reads a snapshot back from a browsable directory tree written by `LooseSnapshotWriter`.
+    pub struct LooseSnapshotReader {
+        root: PathBuf,
+    }
+
+    impl LooseSnapshotReader {
+        pub fn new(root: PathBuf) -> Self {
+            LooseSnapshotReader { root }
+        }
+    }
+
+    impl SnapshotReader for LooseSnapshotReader {
+        fn read_entries(self: Box<Self>) -> Result<Vec<SnapshotEntry>> {
+            let mut entries = Vec::new();
+            walk_dir_into(&self.root, &self.root, &mut entries)?;
+            Ok(entries)
+        }
+    }
+
+    const PACKED_SNAPSHOT_MAGIC: &[u8; 8] = b"LOLBSNAP";
+    const PACKED_SNAPSHOT_VERSION: u32 = 1;
+
+    /// Writes a snapshot out as a single offset-indexed archive file: a version header,
+    /// followed by a `(path, offset, length)` index for every entry, followed by the
+    /// concatenated raw contents of every entry.
+    pub struct PackedSnapshotWriter {
+        path: PathBuf,
+        entries: Vec<SnapshotEntry>,
+    }
+
+    impl PackedSnapshotWriter {
+        pub fn new(path: PathBuf) -> Self {
+            PackedSnapshotWriter {
+                path,
+                entries: Vec::new(),
+            }
+        }
+    }
+
+    impl SnapshotWriter for PackedSnapshotWriter {
+        fn write_entry(&mut self, entry: SnapshotEntry) -> Result<()> {
+            self.entries.push(entry);
+            Ok(())
+        }
+
+        fn finish(self: Box<Self>) -> Result<()> {
+            let mut out = fs::File::create(&self.path)?;
+
+            out.write_all(PACKED_SNAPSHOT_MAGIC)?;
+            out.write_all(&PACKED_SNAPSHOT_VERSION.to_le_bytes())?;
+            out.write_all(&(self.entries.len() as u64).to_le_bytes())?;
+
+            let mut index = Vec::with_capacity(self.entries.len());
+            let mut offset = 0u64;
+            for entry in &self.entries {
+                let length = entry.contents.len() as u64;
+                index.push((entry.path.to_string_lossy().into_owned(), offset, length));
+                offset += length;
+            }
+
+            for (path, entry_offset, length) in &index {
+                let path_bytes = path.as_bytes();
+                out.write_all(&(path_bytes.len() as u64).to_le_bytes())?;
+                out.write_all(path_bytes)?;
+                out.write_all(&entry_offset.to_le_bytes())?;
+                out.write_all(&length.to_le_bytes())?;
+            }
+
+            for entry in &self.entries {
+                out.write_all(&entry.contents)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Reads a snapshot back from an archive file written by `PackedSnapshotWriter`.
+    pub struct PackedSnapshotReader {
+        path: PathBuf,
+    }
+
+    impl PackedSnapshotReader {
+        pub fn new(path: PathBuf) -> Self {
+            PackedSnapshotReader { path }
+        }
+    }
+
+    impl SnapshotReader for PackedSnapshotReader {
+        fn read_entries(self: Box<Self>) -> Result<Vec<SnapshotEntry>> {
+            let bytes = fs::read(&self.path)?;
+
+            let header_len = PACKED_SNAPSHOT_MAGIC.len() + 4 + 8;
+            if bytes.len() < header_len || &bytes[..8] != PACKED_SNAPSHOT_MAGIC {
+                return Err(::std::io::Error::new(
+                    ::std::io::ErrorKind::InvalidData,
+                    format!("{} is not a lolbench snapshot archive", self.path.display()),
+                )
+                .into());
+            }
+
+            let version = read_u32(&bytes, 8)?;
+            if version != PACKED_SNAPSHOT_VERSION {
+                return Err(::std::io::Error::new(
+                    ::std::io::ErrorKind::InvalidData,
+                    format!(
+                        "{} is a v{} snapshot archive, but this build only understands v{}",
+                        self.path.display(),
+                        version,
+                        PACKED_SNAPSHOT_VERSION
+                    ),
+                )
+                .into());
+            }
+
+            let entry_count = read_u64(&bytes, 12)? as usize;
+            let mut offset = 20usize;
+
+            // Each index entry is at least an 8-byte path length, an 8-byte offset, and an
+            // 8-byte length (plus however many path bytes it claims); reject an `entry_count`
+            // that couldn't possibly fit in what's left of the file before trusting it to size
+            // an allocation, so a corrupt archive claiming e.g. `entry_count = u64::MAX` fails
+            // cleanly here instead of panicking with "capacity overflow".
+            const MIN_INDEX_ENTRY_LEN: usize = 24;
+            let max_possible_entries = bytes.len().saturating_sub(offset) / MIN_INDEX_ENTRY_LEN;
+            if entry_count > max_possible_entries {
+                return Err(truncated_archive_error().into());
+            }
+
+            let mut index = Vec::with_capacity(entry_count);
+            for _ in 0..entry_count {
+                let path_len = read_u64(&bytes, offset)? as usize;
+                offset += 8;
+                let path_bytes = slice(&bytes, offset, path_len)?;
+                let path = String::from_utf8(path_bytes.to_vec())
+                    .map_err(|why| ::std::io::Error::new(::std::io::ErrorKind::InvalidData, why))?;
+                offset += path_len;
+                let entry_offset = read_u64(&bytes, offset)? as usize;
+                offset += 8;
+                let length = read_u64(&bytes, offset)? as usize;
+                offset += 8;
+                index.push((path, entry_offset, length));
+            }
+
+            let body_start = offset;
+            let mut entries = Vec::with_capacity(index.len());
+            for (path, entry_offset, length) in index {
+                let start = body_start
+                    .checked_add(entry_offset)
+                    .ok_or_else(|| truncated_archive_error().into())?;
+                let contents = slice(&bytes, start, length)?;
+                entries.push(SnapshotEntry {
+                    path: PathBuf::from(path),
+                    contents: contents.to_vec(),
+                });
+            }
+
+            Ok(entries)
+        }
+    }
+
+    /// Returns `bytes[offset..offset + len]`, or an error instead of panicking if the archive is
+    /// too short to contain it — e.g. truncated by a flaky cross-machine transfer.
+    fn slice(bytes: &[u8], offset: usize, len: usize) -> Result<&[u8]> {
+        let end = offset.checked_add(len).ok_or_else(|| truncated_archive_error().into())?;
+        bytes
+            .get(offset..end)
+            .ok_or_else(|| truncated_archive_error().into())
+    }
+
+    fn read_u32(bytes: &[u8], offset: usize) -> Result<u32> {
+        let mut buf = [0u8; 4];
+        buf.copy_from_slice(slice(bytes, offset, 4)?);
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn read_u64(bytes: &[u8], offset: usize) -> Result<u64> {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(slice(bytes, offset, 8)?);
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    fn truncated_archive_error() -> ::std::io::Error {
+        ::std::io::Error::new(
+            ::std::io::ErrorKind::InvalidData,
+            "lolbench snapshot archive is truncated or corrupt",
+        )
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn unique_temp_dir(label: &str) -> PathBuf {
+            let dir = ::std::env::temp_dir().join(format!(
+                "lolbench-snapshot-test-{}-{}",
+                label,
+                ::std::process::id()
+            ));
+            fs::create_dir_all(&dir).expect("create temp dir");
+            dir
+        }
+
+        #[test]
+        fn packed_snapshot_round_trips_entries() {
+            let dir = unique_temp_dir("round-trip");
+            let archive = dir.join("snapshot.bin");
+
+            let entries = vec![
+                SnapshotEntry {
+                    path: PathBuf::from("index/abc"),
+                    contents: b"[1,2,3]".to_vec(),
+                },
+                SnapshotEntry {
+                    path: PathBuf::from("measurement/def"),
+                    contents: Vec::new(),
+                },
+            ];
+
+            let mut writer: Box<dyn SnapshotWriter> =
+                Box::new(PackedSnapshotWriter::new(archive.clone()));
+            for entry in entries.clone() {
+                writer.write_entry(entry).expect("write entry");
+            }
+            writer.finish().expect("finish archive");
+
+            let reader: Box<dyn SnapshotReader> =
+                Box::new(PackedSnapshotReader::new(archive.clone()));
+            let mut round_tripped = reader.read_entries().expect("read entries");
+            round_tripped.sort_by(|a, b| a.path.cmp(&b.path));
+
+            let mut expected = entries;
+            expected.sort_by(|a, b| a.path.cmp(&b.path));
+
+            assert_eq!(round_tripped.len(), expected.len());
+            for (actual, expected) in round_tripped.iter().zip(expected.iter()) {
+                assert_eq!(actual.path, expected.path);
+                assert_eq!(actual.contents, expected.contents);
+            }
+
+            fs::remove_dir_all(&dir).ok();
+        }
+
+        #[test]
+        fn packed_snapshot_reader_rejects_foreign_files() {
+            let dir = unique_temp_dir("bad-magic");
+            let archive = dir.join("not-a-snapshot.bin");
+            fs::write(&archive, b"definitely not a lolbench snapshot").expect("write garbage");
+
+            let reader: Box<dyn SnapshotReader> = Box::new(PackedSnapshotReader::new(archive));
+            assert!(reader.read_entries().is_err());
+
+            fs::remove_dir_all(&dir).ok();
+        }
+
+        #[test]
+        fn packed_snapshot_reader_rejects_implausible_entry_count_instead_of_panicking() {
+            let dir = unique_temp_dir("huge-entry-count");
+            let archive = dir.join("lying-header.bin");
+
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(PACKED_SNAPSHOT_MAGIC);
+            bytes.extend_from_slice(&PACKED_SNAPSHOT_VERSION.to_le_bytes());
+            bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+            fs::write(&archive, &bytes).expect("write lying header");
+
+            let reader: Box<dyn SnapshotReader> = Box::new(PackedSnapshotReader::new(archive));
+            assert!(reader.read_entries().is_err());
+
+            fs::remove_dir_all(&dir).ok();
+        }
+
+        #[test]
+        fn verify_entry_path_accepts_plain_relative_paths() {
+            assert!(verify_entry_path(Path::new("index/abc")).is_ok());
+        }
+
+        #[test]
+        fn verify_entry_path_rejects_absolute_paths() {
+            assert!(verify_entry_path(Path::new("/etc/passwd")).is_err());
+        }
+
+        #[test]
+        fn verify_entry_path_rejects_parent_dir_traversal() {
+            assert!(verify_entry_path(Path::new("../../etc/passwd")).is_err());
+            assert!(verify_entry_path(Path::new("index/../../escape")).is_err());
+        }
+
+        #[test]
+        fn verify_entry_contents_accepts_a_binary_hash_under_index() {
+            let entry = SnapshotEntry {
+                path: PathBuf::from("index/abc"),
+                contents: b"[1,2,3]".to_vec(),
+            };
+            assert!(verify_entry_contents(&entry).is_ok());
+        }
+
+        #[test]
+        fn verify_entry_contents_rejects_corrupt_bytes() {
+            let entry = SnapshotEntry {
+                path: PathBuf::from("index/abc"),
+                contents: vec![0xff, 0x00, 0xfe, 0x01],
+            };
+            assert!(verify_entry_contents(&entry).is_err());
+        }
+
+        #[test]
+        fn verify_entry_contents_rejects_a_measurement_entry_stored_under_index() {
+            // Valid JSON, and a valid `Result<Estimates, Error>` shape, but not a `Vec<u8>` —
+            // this is what a measurement/index path swap would look like.
+            let entry = SnapshotEntry {
+                path: PathBuf::from("index/abc"),
+                contents: br#"{"Ok":{}}"#.to_vec(),
+            };
+            assert!(verify_entry_contents(&entry).is_err());
+        }
+
+        #[test]
+        fn verify_entry_contents_accepts_a_successful_measurement() {
+            let entry = SnapshotEntry {
+                path: PathBuf::from("measurement/abc"),
+                contents: br#"{"Ok":{}}"#.to_vec(),
+            };
+            assert!(verify_entry_contents(&entry).is_ok());
+        }
+
+        #[test]
+        fn verify_entry_contents_accepts_hex_encoded_history_lines() {
+            let entry = SnapshotEntry {
+                path: PathBuf::from("history/abc.history"),
+                contents: b"deadbeef\n01020304\n".to_vec(),
+            };
+            assert!(verify_entry_contents(&entry).is_ok());
+        }
+
+        #[test]
+        fn verify_entry_contents_rejects_non_hex_history_lines() {
+            let entry = SnapshotEntry {
+                path: PathBuf::from("history/abc.history"),
+                contents: b"not hex\n".to_vec(),
+            };
+            assert!(verify_entry_contents(&entry).is_err());
+        }
+    }
+}
+
+/// A configurable git backend so a `Collector`'s data directory can double as a shared results
+/// repo for distributed CI runners, each contributing only the entries it computed.
+pub mod vcs {
+    use std::path::Path;
+    use std::process::Command;
+
+    use super::Result;
+
+    /// Toggles for the git operations `Collector::run_benches_with_toolchain` performs around a
+    /// batch of benchmarks. Every field defaults to `false`, so a `Collector` over a plain
+    /// (non-git) data directory behaves exactly as before.
+    #[derive(Clone, Debug, Default)]
+    pub struct GitConfig {
+        /// Refuse to `pull` if the data directory's git worktree has uncommitted changes, the
+        /// same way a plain `git pull` would balk at clobbering local edits. Has no effect
+        /// unless `pull` is also set — a dirty worktree between runs (e.g. because `commit` is
+        /// off) is otherwise harmless, since nothing else is about to overwrite it.
+        pub check_clean: bool,
+        /// Pull before running, so results other machines already pushed are picked up.
+        pub pull: bool,
+        /// Commit the entries a run produced.
+        pub commit: bool,
+        /// Push after committing. Has no effect unless `commit` is also set.
+        pub push: bool,
+    }
+
+    impl GitConfig {
+        /// Runs the pre-benchmark checks: if pulling, optionally verifying the worktree is clean
+        /// first, then pulling.
+        pub(crate) fn prepare(&self, dir: &Path) -> Result<()> {
+            if self.pull {
+                if self.check_clean {
+                    self.ensure_clean(dir)?;
+                }
+
+                run_git(dir, &["pull", "--ff-only"])?;
+            }
+
+            Ok(())
+        }
+
+        /// Commits whatever the run just persisted, and pushes if configured to. A no-op unless
+        /// `commit` is set.
+        pub(crate) fn persist(&self, dir: &Path, message: &str) -> Result<()> {
+            if !self.commit {
+                return Ok(());
+            }
+
+            run_git(dir, &["add", "-A"])?;
+
+            if self.worktree_is_clean(dir)? {
+                return Ok(());
+            }
+
+            run_git(dir, &["commit", "--message", message])?;
+
+            if self.push {
+                run_git(dir, &["push"])?;
+            }
+
+            Ok(())
+        }
+
+        fn ensure_clean(&self, dir: &Path) -> Result<()> {
+            if !self.worktree_is_clean(dir)? {
+                return Err(::std::io::Error::new(
+                    ::std::io::ErrorKind::Other,
+                    format!(
+                        "refusing to run: the data directory's git worktree at {} is not clean",
+                        dir.display()
+                    ),
+                )
+                .into());
+            }
+
+            Ok(())
+        }
+
+        fn worktree_is_clean(&self, dir: &Path) -> Result<bool> {
+            let status = run_git(dir, &["status", "--porcelain"])?;
+            Ok(status.trim().is_empty())
+        }
+    }
+
+    /// Hex-encodes a binary hash for use in commit messages and other human-readable output.
+    pub(crate) fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /// Decodes a hash previously encoded with `hex`, e.g. one line of a history file.
+    pub(crate) fn dehex(hex: &str) -> Result<Vec<u8>> {
+        if hex.len() % 2 != 0 {
+            return Err(malformed_hex_error(hex).into());
+        }
+
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| malformed_hex_error(hex)))
+            .collect::<::std::result::Result<Vec<u8>, _>>()
+            .map_err(|err| err.into())
+    }
+
+    fn malformed_hex_error(hex: &str) -> ::std::io::Error {
+        ::std::io::Error::new(
+            ::std::io::ErrorKind::InvalidData,
+            format!("{} is not a valid hex-encoded hash", hex),
+        )
+    }
+
+    /// Runs `git <args>` in `dir`, returning stdout on success or an error including stderr.
+    fn run_git(dir: &Path, args: &[&str]) -> Result<String> {
+        let output = Command::new("git").args(args).current_dir(dir).output()?;
+
+        if !output.status.success() {
+            return Err(::std::io::Error::new(
+                ::std::io::ErrorKind::Other,
+                format!(
+                    "git {} failed in {}: {}",
+                    args.join(" "),
+                    dir.display(),
+                    String::from_utf8_lossy(&output.stderr)
+                ),
+            )
+            .into());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+/// Renders Markdown comparison tables of already-collected estimates across toolchains, without
+/// building or running anything.
+pub mod report {
+    use std::collections::BTreeMap;
+    use std::fmt::Write;
+
+    use super::Collector;
+    use super::Result;
+    use run_plan::RunPlan;
+    use storage::Statistic;
+    use toolchain::Toolchain;
+
+    /// One row per benchmark, one column per `Toolchain`, cells showing the nanosecond point
+    /// estimate with its confidence interval. If `baseline` is given, a trailing "Δ" column is
+    /// added per non-baseline toolchain, showing its percent change vs. the baseline.
+    ///
+    /// This only reads data the `Collector` has already stored for the given `RunPlan`s — it
+    /// never builds or runs anything, so callers should run `Collector::run` first.
+    pub fn markdown_comparison_table(
+        collector: &Collector,
+        run_plans_by_toolchain: &BTreeMap<Toolchain, Vec<RunPlan>>,
+        baseline: Option<&Toolchain>,
+    ) -> Result<String> {
+        let toolchains: Vec<&Toolchain> = run_plans_by_toolchain.keys().collect();
+        let deltas: Vec<&Toolchain> = match baseline {
+            Some(baseline) => toolchains
+                .iter()
+                .cloned()
+                .filter(|&t| t != baseline)
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let mut rows: BTreeMap<String, BTreeMap<Toolchain, Statistic>> = BTreeMap::new();
+
+        for (toolchain, run_plans) in run_plans_by_toolchain {
+            for rp in run_plans {
+                let benchmark_id = format!("{}::{}", rp.benchmark.crate_name, rp.benchmark.name);
+
+                if let Some(estimates) = collector.stored_estimates(rp)? {
+                    if let Some(nanoseconds) = estimates.get("nanoseconds") {
+                        rows.entry(benchmark_id)
+                            .or_insert_with(BTreeMap::new)
+                            .insert(toolchain.clone(), nanoseconds.clone());
+                    }
+                }
+            }
+        }
+
+        let mut table = String::new();
+
+        write!(table, "| benchmark |").expect("writing to a String cannot fail");
+        for toolchain in &toolchains {
+            write!(table, " {:?} |", toolchain).expect("writing to a String cannot fail");
+        }
+        for toolchain in &deltas {
+            write!(table, " Δ {:?} vs {:?} |", toolchain, baseline.unwrap())
+                .expect("writing to a String cannot fail");
+        }
+        table.push('\n');
+
+        write!(table, "|---|").expect("writing to a String cannot fail");
+        for _ in toolchains.iter().chain(deltas.iter()) {
+            write!(table, "---|").expect("writing to a String cannot fail");
+        }
+        table.push('\n');
+
+        for (benchmark_id, by_toolchain) in &rows {
+            write!(table, "| {} |", benchmark_id).expect("writing to a String cannot fail");
+
+            for toolchain in &toolchains {
+                write!(table, " {} |", format_cell(by_toolchain.get(*toolchain)))
+                    .expect("writing to a String cannot fail");
+            }
+
+            for toolchain in &deltas {
+                let change = format_change(
+                    by_toolchain.get(baseline.unwrap()),
+                    by_toolchain.get(*toolchain),
+                );
+                write!(table, " {} |", change).expect("writing to a String cannot fail");
+            }
+
+            table.push('\n');
+        }
+
+        Ok(table)
+    }
+
+    fn format_cell(stat: Option<&Statistic>) -> String {
+        match stat {
+            Some(stat) => {
+                let ci_width =
+                    (stat.confidence_interval.upper_bound - stat.confidence_interval.lower_bound)
+                        / 2.0;
+                format!("{:.1}ns ± {:.1}", stat.point_estimate, ci_width)
+            }
+            None => String::from("—"),
+        }
+    }
+
+    fn format_change(baseline: Option<&Statistic>, stat: Option<&Statistic>) -> String {
+        match (baseline, stat) {
+            (Some(baseline), Some(stat)) => {
+                let relative_change =
+                    (stat.point_estimate - baseline.point_estimate) / baseline.point_estimate;
+                format!("{:+.1}%", relative_change * 100.0)
+            }
+            _ => String::from("—"),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        use serde_json;
+
+        fn stat(point_estimate: f64, lower_bound: f64, upper_bound: f64) -> Statistic {
+            serde_json::from_str(&format!(
+                r#"{{
+                    "confidence_interval": {{
+                        "confidence_level": 0.95,
+                        "lower_bound": {},
+                        "upper_bound": {}
+                    }},
+                    "point_estimate": {},
+                    "standard_error": 0.0
+                }}"#,
+                lower_bound, upper_bound, point_estimate
+            ))
+            .expect("valid Statistic JSON")
+        }
+
+        #[test]
+        fn format_cell_renders_point_estimate_with_ci_width() {
+            let s = stat(100.0, 90.0, 110.0);
+            assert_eq!(format_cell(Some(&s)), "100.0ns ± 10.0");
+        }
+
+        #[test]
+        fn format_cell_renders_dash_when_missing() {
+            assert_eq!(format_cell(None), "—");
+        }
+
+        #[test]
+        fn format_change_renders_signed_percent() {
+            let baseline = stat(100.0, 90.0, 110.0);
+            let new = stat(120.0, 110.0, 130.0);
+            assert_eq!(format_change(Some(&baseline), Some(&new)), "+20.0%");
+        }
+
+        #[test]
+        fn format_change_renders_negative_percent() {
+            let baseline = stat(100.0, 90.0, 110.0);
+            let new = stat(80.0, 70.0, 90.0);
+            assert_eq!(format_change(Some(&baseline), Some(&new)), "-20.0%");
+        }
+
+        #[test]
+        fn format_change_renders_dash_when_either_side_is_missing() {
+            let baseline = stat(100.0, 90.0, 110.0);
+            assert_eq!(format_change(None, Some(&baseline)), "—");
+            assert_eq!(format_change(Some(&baseline), None), "—");
+            assert_eq!(format_change(None, None), "—");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stat(point_estimate: f64, lower_bound: f64, upper_bound: f64) -> Statistic {
+        serde_json::from_str(&format!(
+            r#"{{
+                "confidence_interval": {{
+                    "confidence_level": 0.95,
+                    "lower_bound": {},
+                    "upper_bound": {}
+                }},
+                "point_estimate": {},
+                "standard_error": 0.0
+            }}"#,
+            lower_bound, upper_bound, point_estimate
+        ))
+        .expect("valid Statistic JSON")
+    }
+
+    #[test]
+    fn run_failure_classifies_compile_errors_as_permanent() {
+        assert!(!ErrorKind::Run(String::from("error[E0382]: use of moved value")).is_retryable());
+        assert!(!ErrorKind::Run(String::from("could not compile `lolbench`")).is_retryable());
+    }
+
+    #[test]
+    fn run_failure_classifies_transient_io_as_retryable() {
+        assert!(ErrorKind::Run(String::from("connection reset by peer")).is_retryable());
+        assert!(ErrorKind::Run(String::from("resource temporarily unavailable (os error 11)"))
+            .is_retryable());
+    }
+
+    #[test]
+    fn run_failure_classifies_unrecognized_messages_as_permanent() {
+        assert!(!ErrorKind::Run(String::from("the benchmark panicked")).is_retryable());
+    }
+
+    #[test]
+    fn postprocess_failure_classifies_missing_output_as_retryable() {
+        assert!(ErrorKind::PostProcess(String::from(
+            "No such file or directory (os error 2)"
+        ))
+        .is_retryable());
+    }
+
+    #[test]
+    fn postprocess_failure_classifies_variance_warnings_as_retryable() {
+        assert!(
+            ErrorKind::PostProcess(String::from("Unable to complete 100 samples")).is_retryable()
+        );
+    }
+
+    #[test]
+    fn postprocess_failure_classifies_parse_errors_as_permanent() {
+        assert!(!ErrorKind::PostProcess(String::from("expected value at line 1 column 1"))
+            .is_retryable());
+    }
+
+    #[test]
+    fn error_is_exhausted_once_retries_are_used_up() {
+        let mut err = Error::new(ErrorKind::Run(String::from("connection reset")), 0);
+        assert!(!err.is_exhausted());
+
+        err.num_retries = err.max_retries;
+        assert!(err.is_exhausted());
+    }
+
+    #[test]
+    fn error_is_exhausted_immediately_when_not_retryable() {
+        let err = Error::new(ErrorKind::Run(String::from("could not compile")), 0);
+        assert!(err.is_exhausted());
+    }
+
+    #[test]
+    fn classify_change_flags_regression_when_cis_do_not_overlap() {
+        let old = stat(100.0, 95.0, 105.0);
+        let new = stat(120.0, 115.0, 125.0);
+
+        let change = Collector::classify_change(&old, &new, DEFAULT_NOISE_THRESHOLD);
+
+        assert_eq!(change.change, Change::Regressed);
+        assert!((change.relative_change - 0.2).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn classify_change_flags_improvement_when_cis_do_not_overlap() {
+        let old = stat(100.0, 95.0, 105.0);
+        let new = stat(80.0, 75.0, 85.0);
+
+        let change = Collector::classify_change(&old, &new, DEFAULT_NOISE_THRESHOLD);
+
+        assert_eq!(change.change, Change::Improved);
+    }
+
+    #[test]
+    fn classify_change_treats_overlapping_cis_as_noise_even_with_a_large_point_delta() {
+        let old = stat(100.0, 50.0, 150.0);
+        let new = stat(130.0, 80.0, 180.0);
+
+        let change = Collector::classify_change(&old, &new, DEFAULT_NOISE_THRESHOLD);
+
+        assert_eq!(change.change, Change::NoChange);
+    }
+
+    #[test]
+    fn classify_change_treats_small_relative_change_as_noise() {
+        let old = stat(100.0, 99.5, 100.5);
+        let new = stat(101.0, 100.6, 101.4);
+
+        let change = Collector::classify_change(&old, &new, DEFAULT_NOISE_THRESHOLD);
+
+        assert_eq!(change.change, Change::NoChange);
+    }
+
+    #[test]
+    fn classify_change_against_a_zero_baseline_does_not_divide_by_zero() {
+        let old = stat(0.0, 0.0, 0.0);
+        let new = stat(5.0, 4.0, 6.0);
+
+        let change = Collector::classify_change(&old, &new, DEFAULT_NOISE_THRESHOLD);
+
+        assert_eq!(change.change, Change::Regressed);
+        assert!(change.relative_change.is_finite());
+    }
+
+    #[test]
+    fn classify_change_against_a_zero_baseline_with_a_near_zero_new_value_is_noise() {
+        let old = stat(0.0, 0.0, 0.0);
+        let new = stat(0.001, 0.0005, 0.0015);
+
+        let change = Collector::classify_change(&old, &new, DEFAULT_NOISE_THRESHOLD);
+
+        assert_eq!(change.change, Change::NoChange);
+        assert!(change.relative_change.is_finite());
+    }
+
+    #[test]
+    fn vcs_hex_encodes_bytes_lowercase() {
+        assert_eq!(vcs::hex(&[0xde, 0xad, 0xbe, 0xef]), "deadbeef");
+        assert_eq!(vcs::hex(&[]), "");
+    }
+
+    #[test]
+    fn vcs_dehex_round_trips_vcs_hex() {
+        let bytes = vec![0x00, 0x01, 0xff, 0x7a];
+        assert_eq!(vcs::dehex(&vcs::hex(&bytes)).expect("valid hex"), bytes);
+    }
+
+    #[test]
+    fn vcs_dehex_rejects_odd_length_input() {
+        assert!(vcs::dehex("abc").is_err());
+    }
+
+    #[test]
+    fn vcs_dehex_rejects_non_hex_characters() {
+        assert!(vcs::dehex("zz").is_err());
+    }
+
+    #[test]
+    fn format_batch_commit_message_lists_toolchain_and_each_entry() {
+        let entries = vec![
+            (String::from("crate_a"), String::from("bench_1"), vec![0xab, 0xcd]),
+            (String::from("crate_b"), String::from("bench_2"), vec![0x01]),
+        ];
+
+        let message = Collector::format_batch_commit_message("Stable", 2, &entries);
+
+        assert_eq!(
+            message,
+            "Stable: 2 benchmark(s)\n- crate_a::bench_1 (abcd)\n- crate_b::bench_2 (01)"
+        );
+    }
+
+    #[test]
+    fn format_batch_commit_message_omits_entries_with_no_hash_yet() {
+        let message = Collector::format_batch_commit_message("Stable", 3, &[]);
+
+        assert_eq!(message, "Stable: 3 benchmark(s)");
+    }
 }